@@ -0,0 +1,761 @@
+use std::fmt;
+
+#[cfg(feature = "pyo3")]
+mod python;
+
+/// An element of the quadratic extension field \( \mathbb{F}_{p^2} \),
+/// obtained by adjoining a root `t` of the irreducible polynomial
+/// `t^2 - N` to the base prime field \( \mathbb{F}_p \) (i.e. `t^2 = N`).
+///
+/// `p` is the base prime and `n` is a quadratic non-residue mod `p`, so
+/// `t^2 = n` has no root in \( \mathbb{F}_p \) and the extension is
+/// genuinely degree 2. Elements are stored as `a + b*t`.
+///
+/// `p` and `n` are plain runtime fields rather than const generics
+/// (`Fp2<const P: u8, const N: u8>`), and base/extension arithmetic is not
+/// split behind a trait the way pasta_curves/primeorder do it: the CLI
+/// lets a user pick `p`/`n` on the command line, which a const-generic
+/// field couldn't represent without monomorphizing a new type per curve.
+/// Base-field arithmetic is small enough here (`mod_inverse`, `mod_pow`)
+/// that it's inlined directly rather than factored into a separate trait.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fp2 {
+    p: u8,
+    n: u8,
+    a: u8, // Coefficient for 1
+    b: u8, // Coefficient for t
+}
+
+impl fmt::Display for Fp2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.a, self.b) {
+            (0, 0) => write!(f, "0"),
+            (a, 0) => write!(f, "{}", a),
+            (0, b) => write!(f, "{}t", b),
+            (a, b) => write!(f, "{} + {}t", a, b),
+        }
+    }
+}
+
+// `add`/`sub`/`mul`/`div` take `(self, Fp2) -> Fp2` like the matching
+// std::ops traits, but every one of them needs the field's `p`/`n` to
+// normalize the result, which those traits' signatures can't carry.
+#[allow(clippy::should_implement_trait)]
+impl Fp2 {
+    pub fn new(p: u8, n: u8, a: u8, b: u8) -> Self {
+        Fp2 {
+            p,
+            n,
+            a: a % p,
+            b: b % p,
+        }
+    }
+
+    pub fn zero(p: u8, n: u8) -> Self {
+        Fp2::new(p, n, 0, 0)
+    }
+
+    pub fn one(p: u8, n: u8) -> Self {
+        Fp2::new(p, n, 1, 0)
+    }
+
+    pub fn add(self, other: Fp2) -> Fp2 {
+        Fp2::new(self.p, self.n, (self.a + other.a) % self.p, (self.b + other.b) % self.p)
+    }
+
+    pub fn sub(self, other: Fp2) -> Fp2 {
+        Fp2::new(
+            self.p,
+            self.n,
+            (self.a + self.p - other.a) % self.p,
+            (self.b + self.p - other.b) % self.p,
+        )
+    }
+
+    pub fn mul(self, other: Fp2) -> Fp2 {
+        let a = self.a as i32;
+        let b = self.b as i32;
+        let c = other.a as i32;
+        let d = other.b as i32;
+        let p = self.p as i32;
+        let n = self.n as i32;
+        // (a + b*t) * (c + d*t) = ac + (ad+bc)t + bd*t^2 = (ac + n*bd) + (ad+bc)t.
+        let ac = a * c;
+        let bd = b * d;
+        let ad_plus_bc = a * d + b * c;
+        let new_a = (ac + n * bd).rem_euclid(p) as u8;
+        let new_b = ad_plus_bc.rem_euclid(p) as u8;
+        Fp2::new(self.p, self.n, new_a, new_b)
+    }
+
+    pub fn div(self, other: Fp2) -> Fp2 {
+        let inv = other.inverse();
+        self.mul(inv)
+    }
+
+    pub fn inverse(self) -> Fp2 {
+        // For u = a + b*t, its inverse is (a - b*t)/(a^2 - n*b^2), since t^2 = n.
+        let a = self.a as i32;
+        let b = self.b as i32;
+        let n = self.n as i32;
+        let p = self.p as i32;
+        let denominator = (a * a - n * b * b).rem_euclid(p) as u8;
+        let inv_denominator = Fp2::mod_inverse(denominator, self.p);
+        let new_a = (a * inv_denominator as i32).rem_euclid(p) as u8;
+        let new_b = (self.p - (b * inv_denominator as i32).rem_euclid(p) as u8) % self.p;
+        Fp2::new(self.p, self.n, new_a, new_b)
+    }
+
+    fn mod_inverse(x: u8, p: u8) -> u8 {
+        for i in 1..p {
+            if (x as u16 * i as u16) % p as u16 == 1 {
+                return i;
+            }
+        }
+        panic!("No modular inverse found!");
+    }
+
+    fn mod_pow(base: u8, exp: u8, modulus: u8) -> u8 {
+        let mut result: u16 = 1;
+        let mut base = base as u16 % modulus as u16;
+        let mut exp = exp;
+        let modulus = modulus as u16;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = (result * base) % modulus;
+            }
+            base = (base * base) % modulus;
+            exp >>= 1;
+        }
+        result as u8
+    }
+
+    /// Frobenius automorphism: in characteristic `p`, `(a + b*t)^p = a + b*t^p`.
+    /// Since `t^2 = n`, `t^p = t^(p-1) * t = n^((p-1)/2) * t`.
+    pub fn frobenius(self) -> Fp2 {
+        let n_pow = Fp2::mod_pow(self.n, (self.p - 1) / 2, self.p);
+        Fp2::new(self.p, self.n, self.a, (self.b as u16 * n_pow as u16 % self.p as u16) as u8)
+    }
+
+    /// Raises this element to the given exponent via square-and-multiply.
+    pub fn pow(self, mut exp: u32) -> Fp2 {
+        let mut result = Fp2::one(self.p, self.n);
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul(base);
+            }
+            base = base.mul(base);
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+/// A point on the elliptic curve `y^2 = x^3 + ax + b`, held in homogeneous
+/// projective coordinates `(X : Y : Z)` (affine `x = X/Z`, `y = Y/Z`). The
+/// point at infinity is `(0 : 1 : 0)`.
+///
+/// Projective coordinates let [`point_add`] use the complete
+/// Renes–Costello–Batina addition law, which needs no special-casing for
+/// doubling, the identity, or inverse points, and defers every inversion
+/// to a single call to [`Point::to_affine`].
+#[derive(Clone, Copy, Debug)]
+pub struct Point {
+    x: Fp2,
+    y: Fp2,
+    z: Fp2,
+}
+
+impl Point {
+    pub fn from_affine(x: Fp2, y: Fp2) -> Self {
+        Point {
+            x,
+            y,
+            z: Fp2::one(x.p, x.n),
+        }
+    }
+
+    pub fn is_at_infinity(&self) -> bool {
+        self.z.a == 0 && self.z.b == 0
+    }
+
+    pub fn at_infinity(p: u8, n: u8) -> Self {
+        Point {
+            x: Fp2::zero(p, n),
+            y: Fp2::one(p, n),
+            z: Fp2::zero(p, n),
+        }
+    }
+
+    /// Converts to affine coordinates with a single field inversion,
+    /// returning `None` for the point at infinity.
+    pub fn to_affine(self) -> Option<(Fp2, Fp2)> {
+        if self.is_at_infinity() {
+            return None;
+        }
+        let z_inv = self.z.inverse();
+        Some((self.x.mul(z_inv), self.y.mul(z_inv)))
+    }
+}
+
+impl PartialEq for Point {
+    /// Equality of projective points, compared up to scaling: `(X1:Y1:Z1) ==
+    /// (X2:Y2:Z2)` iff `X1*Z2 == X2*Z1` and `Y1*Z2 == Y2*Z1`.
+    fn eq(&self, other: &Self) -> bool {
+        match (self.is_at_infinity(), other.is_at_infinity()) {
+            (true, true) => true,
+            (true, false) | (false, true) => false,
+            (false, false) => {
+                self.x.mul(other.z) == other.x.mul(self.z)
+                    && self.y.mul(other.z) == other.y.mul(self.z)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.to_affine() {
+            None => write!(f, "Point at infinity"),
+            Some((x, y)) => write!(f, "({}, {})", x, y),
+        }
+    }
+}
+
+/// Normalizes many projective points to affine (`Z = 1`) in place using
+/// Montgomery's trick: a running prefix product of all `Z`-coordinates is
+/// inverted once, then walked backwards to recover each individual `1/Z_i`.
+/// This replaces what would otherwise be one inversion per point with a
+/// single inversion for the whole batch. Points at infinity (`Z = 0`) have
+/// no affine form and are skipped, left exactly as they were.
+pub fn batch_normalize(points: &mut [Point]) {
+    let Some(first) = points.first() else {
+        return;
+    };
+    let (p, n) = (first.z.p, first.z.n);
+
+    let mut prefix_products = vec![Fp2::one(p, n); points.len()];
+    let mut acc = Fp2::one(p, n);
+    for (point, product) in points.iter().zip(prefix_products.iter_mut()) {
+        *product = acc;
+        if !point.is_at_infinity() {
+            acc = acc.mul(point.z);
+        }
+    }
+
+    // `acc` is now the product of every non-infinity `Z`; invert it once.
+    acc = acc.inverse();
+
+    for (point, product) in points.iter_mut().zip(prefix_products.iter()).rev() {
+        if point.is_at_infinity() {
+            continue;
+        }
+        let z_inv = acc.mul(*product);
+        acc = acc.mul(point.z);
+        point.x = point.x.mul(z_inv);
+        point.y = point.y.mul(z_inv);
+        point.z = Fp2::one(p, n);
+    }
+}
+
+/// Point addition on the curve `y^2 = x^3 + ax + b`, using the complete
+/// projective addition formulas of Renes, Costello and Batina (2015),
+/// "Complete addition formulas for prime order elliptic curves", Algorithm 1.
+/// The same straight-line sequence correctly computes doubling, addition of
+/// distinct points, and addition with the identity, with no branching.
+pub fn point_add(p: Point, q: Point, a: Fp2, b: Fp2) -> Point {
+    let (x1, y1, z1) = (p.x, p.y, p.z);
+    let (x2, y2, z2) = (q.x, q.y, q.z);
+    let b3 = b.add(b).add(b);
+
+    let mut t0 = x1.mul(x2);
+    let mut t1 = y1.mul(y2);
+    let mut t2 = z1.mul(z2);
+    let mut t3 = x1.add(y1);
+    let mut t4 = x2.add(y2);
+    t3 = t3.mul(t4);
+    t4 = t0.add(t1);
+    t3 = t3.sub(t4);
+    t4 = x1.add(z1);
+    let mut t5 = x2.add(z2);
+    t4 = t4.mul(t5);
+    t5 = t0.add(t2);
+    t4 = t4.sub(t5);
+    t5 = y1.add(z1);
+    let mut x3 = y2.add(z2);
+    t5 = t5.mul(x3);
+    x3 = t1.add(t2);
+    t5 = t5.sub(x3);
+    let mut z3 = a.mul(t4);
+    x3 = b3.mul(t2);
+    z3 = x3.add(z3);
+    x3 = t1.sub(z3);
+    z3 = t1.add(z3);
+    let mut y3 = x3.mul(z3);
+    t1 = t0.add(t0);
+    t1 = t1.add(t0);
+    t2 = a.mul(t2);
+    t4 = b3.mul(t4);
+    t1 = t1.add(t2);
+    t2 = t0.sub(t2);
+    t2 = a.mul(t2);
+    t4 = t4.add(t2);
+    t0 = t1.mul(t4);
+    y3 = y3.add(t0);
+    t0 = t5.mul(t4);
+    x3 = t3.mul(x3);
+    x3 = x3.sub(t0);
+    t0 = t3.mul(t1);
+    z3 = t5.mul(z3);
+    z3 = z3.add(t0);
+
+    Point { x: x3, y: y3, z: z3 }
+}
+
+/// Scalar multiplication using double-and-add.
+pub fn point_mul(k: u8, p: Point, a: Fp2, b: Fp2) -> Point {
+    let mut result = Point::at_infinity(a.p, a.n);
+    let mut base = p;
+    let mut k = k;
+    while k > 0 {
+        if k & 1 == 1 {
+            result = point_add(result, base, a, b);
+        }
+        base = point_add(base, base, a, b);
+        k >>= 1;
+    }
+    result
+}
+
+/// Applies the Frobenius endomorphism to a point:
+/// (x, y) -> (x^p, y^p).
+pub fn point_frobenius(p: Point) -> Point {
+    match p.to_affine() {
+        None => Point::at_infinity(p.x.p, p.x.n),
+        Some((x, y)) => Point::from_affine(x.frobenius(), y.frobenius()),
+    }
+}
+
+/// Finds all full r‑torsion points (P such that rP = O) by iterating over the field.
+pub fn find_full_r_torsion_points(r: u8, a: Fp2, b: Fp2, field_elements: &[Fp2]) -> Vec<Point> {
+    let mut torsion_points = Vec::new();
+    for x in field_elements.iter() {
+        let x_cubed = x.mul(*x).mul(*x);
+        let rhs = x_cubed.add(a.mul(*x)).add(b);
+        for y in field_elements.iter() {
+            if y.mul(*y) == rhs {
+                let point = Point::from_affine(*x, *y);
+                if point_mul(r, point, a, b).is_at_infinity() {
+                    torsion_points.push(point);
+                }
+            }
+        }
+    }
+    torsion_points.push(Point::at_infinity(a.p, a.n));
+    batch_normalize(&mut torsion_points);
+    torsion_points
+}
+
+/// Finds all points in G₂:
+/// those points P for which (x^p, y^p) = p·point (the Frobenius eigenspace).
+pub fn find_g2_points(a: Fp2, b: Fp2, field_elements: &[Fp2]) -> Vec<Point> {
+    let mut g2_points = Vec::new();
+    for x in field_elements.iter() {
+        let x_cubed = x.mul(*x).mul(*x);
+        let rhs = x_cubed.add(a.mul(*x)).add(b);
+        for y in field_elements.iter() {
+            if y.mul(*y) == rhs {
+                let p = Point::from_affine(*x, *y);
+                let frob = point_frobenius(p);
+                let p_times_p = point_mul(a.p, p, a, b);
+                if frob == p_times_p {
+                    g2_points.push(p);
+                }
+            }
+        }
+    }
+    // The point at infinity trivially satisfies the condition.
+    g2_points.push(Point::at_infinity(a.p, a.n));
+    batch_normalize(&mut g2_points);
+    g2_points
+}
+
+/// Computes the (reduced) Tate pairing `e(P, Q)` of an `r`-torsion point `P`
+/// against `Q`, via Miller's algorithm.
+///
+/// Builds the Miller function `f_{r,P}` evaluated at `Q` by walking the bits
+/// of `r` from the second-most-significant down to the least, accumulating a
+/// doubling update at every step and an addition update whenever the current
+/// bit is set. The result is then raised to the power `(p^2 - 1) / r` so it
+/// lands in the order-`r` subgroup `μ_r ⊂ F_{p^2}^*`.
+///
+/// Every line evaluated by the Miller loop is a vertical or non-vertical line
+/// through the current accumulator point `T`; if `Q` happens to lie on that
+/// exact line (most commonly when self-pairing `e(P, P)`, since `T` then
+/// visits multiples of `P`), the line evaluates to zero and the pairing is
+/// degenerate for this choice of `Q`. Rather than divide by zero, this
+/// returns an error so the caller can retry with a shifted `Q` (e.g. `Q + R`
+/// for some fixed point `R` outside the subgroup generated by `P`).
+pub fn tate_pairing(p: Point, q: Point, r: u8, a: Fp2, b: Fp2) -> Result<Fp2, String> {
+    let (xp, yp) = match p.to_affine() {
+        None => return Ok(Fp2::one(a.p, a.n)),
+        Some(coords) => coords,
+    };
+    let (xq, yq) = match q.to_affine() {
+        None => return Ok(Fp2::one(a.p, a.n)),
+        Some(coords) => coords,
+    };
+    let zero = Fp2::zero(a.p, a.n);
+    let mut f = Fp2::one(a.p, a.n);
+    let mut t = p;
+
+    let degenerate = || {
+        "Q lies on a line the Miller loop evaluates through; choose a Q outside \
+         the subgroup generated by P (e.g. Q + R for a fixed R)"
+            .to_string()
+    };
+
+    let bit_len = 8 - r.leading_zeros();
+    for i in (0..bit_len.saturating_sub(1)).rev() {
+        // Doubling update: λ = (3*x_T^2 + a) / (2*y_T).
+        let (xt, yt) = t.to_affine().ok_or_else(degenerate)?;
+        if yt == zero {
+            // T is a 2-torsion point, so 2T = O and the tangent at T is the
+            // vertical line through T; by convention v_O = 1, so the update
+            // is f^2 * l(Q) with no division.
+            let v = xq.sub(xt);
+            if v == zero {
+                return Err(degenerate());
+            }
+            f = f.mul(f).mul(v);
+            t = Point::at_infinity(a.p, a.n);
+        } else {
+            let lambda = xt
+                .mul(xt)
+                .mul(Fp2::new(a.p, a.n, 3, 0))
+                .add(a)
+                .div(yt.mul(Fp2::new(a.p, a.n, 2, 0)));
+            let two_t = point_add(t, t, a, b);
+            let l = yq.sub(yt).sub(lambda.mul(xq.sub(xt)));
+            let v = match two_t.to_affine() {
+                Some((x2t, _)) => xq.sub(x2t),
+                None => Fp2::one(a.p, a.n),
+            };
+            if l == zero || v == zero {
+                return Err(degenerate());
+            }
+            f = f.mul(f).mul(l).div(v);
+            t = two_t;
+        }
+
+        if (r >> i) & 1 == 1 {
+            // Addition update: λ = (y_P - y_T) / (x_P - x_T).
+            let (xt, yt) = t.to_affine().ok_or_else(degenerate)?;
+            if xt == xp && yt != yp {
+                // T == -P, so T+P = O and the line through them is vertical;
+                // as above, v_O = 1, so the update is f * l(Q) with no
+                // division.
+                let v = xq.sub(xt);
+                if v == zero {
+                    return Err(degenerate());
+                }
+                f = f.mul(v);
+                t = Point::at_infinity(a.p, a.n);
+            } else {
+                let lambda = if xt == xp {
+                    // T == P exactly: the secant degenerates to the tangent.
+                    xt.mul(xt)
+                        .mul(Fp2::new(a.p, a.n, 3, 0))
+                        .add(a)
+                        .div(yt.mul(Fp2::new(a.p, a.n, 2, 0)))
+                } else {
+                    yp.sub(yt).div(xp.sub(xt))
+                };
+                let t_plus_p = point_add(t, p, a, b);
+                let l = yq.sub(yt).sub(lambda.mul(xq.sub(xt)));
+                let v = match t_plus_p.to_affine() {
+                    Some((xtp, _)) => xq.sub(xtp),
+                    None => Fp2::one(a.p, a.n),
+                };
+                if l == zero || v == zero {
+                    return Err(degenerate());
+                }
+                f = f.mul(l).div(v);
+                t = t_plus_p;
+            }
+        }
+    }
+
+    // Final exponentiation, projecting f into μ_r ⊂ F_{p^2}^*.
+    let prime = a.p as u32;
+    let exponent = (prime * prime - 1) / (r as u32);
+    Ok(f.pow(exponent))
+}
+
+/// Parses a quadratic-extension element written as `"a"`, `"b t"`/`"b*t"`, or
+/// `"a + b*t"` (whitespace is ignored) into an [`Fp2`] over the given field.
+pub fn parse_fp2(input: &str, p: u8, n: u8) -> Result<Fp2, String> {
+    let s: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    if let Some(idx) = s.find('+') {
+        let a_part = &s[..idx];
+        let b_part = &s[idx + 1..];
+        let a_val = parse_coefficient(a_part, p)?;
+        let b_val = parse_t_term(b_part, p)?;
+        Ok(Fp2::new(p, n, a_val, b_val))
+    } else if s.ends_with('t') {
+        let b_val = parse_t_term(&s, p)?;
+        Ok(Fp2::new(p, n, 0, b_val))
+    } else {
+        let a_val = parse_coefficient(&s, p)?;
+        Ok(Fp2::new(p, n, a_val, 0))
+    }
+}
+
+fn parse_coefficient(s: &str, p: u8) -> Result<u8, String> {
+    s.parse::<i32>()
+        .map(|v| v.rem_euclid(p as i32) as u8)
+        .map_err(|_| format!("invalid coefficient: \"{}\"", s))
+}
+
+fn parse_t_term(s: &str, p: u8) -> Result<u8, String> {
+    let trimmed = s.strip_suffix('t').ok_or_else(|| format!("expected a \"t\" term: \"{}\"", s))?;
+    let trimmed = trimmed.strip_suffix('*').unwrap_or(trimmed);
+    if trimmed.is_empty() {
+        return Ok(1);
+    }
+    parse_coefficient(trimmed, p)
+}
+
+/// Returns `true` if `p` is a prime number.
+pub fn is_prime(p: u8) -> bool {
+    if p < 2 {
+        return false;
+    }
+    let p32 = p as u32;
+    let mut d = 2;
+    while d * d <= p32 {
+        if p32.is_multiple_of(d) {
+            return false;
+        }
+        d += 1;
+    }
+    true
+}
+
+/// Returns `true` if `n` is a genuine quadratic non-residue mod the odd
+/// prime `p`, i.e. `t^2 = n` has no solution in `F_p` and the extension
+/// `F_{p^2}` is honestly degree 2. By Euler's criterion this holds iff
+/// `n^((p-1)/2) ≡ -1 (mod p)`.
+pub fn is_quadratic_nonresidue(n: u8, p: u8) -> bool {
+    !n.is_multiple_of(p) && Fp2::mod_pow(n % p, (p - 1) / 2, p) == p - 1
+}
+
+/// Validates that `p`/`n` describe a genuine quadratic extension field
+/// before they're used as a modulus anywhere else: `p` must be an odd
+/// prime, and `n` a quadratic non-residue mod `p`.
+pub fn validate_field(p: u8, n: u8) -> Result<(), String> {
+    if p < 3 || !is_prime(p) {
+        return Err(format!("p must be an odd prime, got {}", p));
+    }
+    if !is_quadratic_nonresidue(n, p) {
+        return Err(format!("n must be a quadratic non-residue mod {}, got {}", p, n));
+    }
+    Ok(())
+}
+
+/// Parses a curve point written as `"x,y"`, where each coordinate is an
+/// [`Fp2`] expression accepted by [`parse_fp2`].
+pub fn parse_point(input: &str, p: u8, n: u8) -> Result<Point, String> {
+    let (x_str, y_str) = input
+        .split_once(',')
+        .ok_or_else(|| format!("expected \"x,y\", got \"{}\"", input))?;
+    let x = parse_fp2(x_str, p, n)?;
+    let y = parse_fp2(y_str, p, n)?;
+    Ok(Point::from_affine(x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The CLI's default curve: y^2 = x^3 + x + 1 over F_{5^2}, t^2 = 3, r = 3.
+    const P: u8 = 5;
+    const N: u8 = 3;
+
+    fn default_curve() -> (Fp2, Fp2) {
+        (Fp2::new(P, N, 1, 0), Fp2::new(P, N, 1, 0))
+    }
+
+    #[test]
+    fn validate_field_accepts_the_default_curve() {
+        assert!(validate_field(P, N).is_ok());
+    }
+
+    #[test]
+    fn validate_field_rejects_non_prime_p() {
+        assert!(validate_field(0, 3).is_err());
+        assert!(validate_field(1, 3).is_err());
+        assert!(validate_field(4, 3).is_err());
+    }
+
+    #[test]
+    fn validate_field_rejects_quadratic_residue_n() {
+        // 4 is a perfect square, so it's a quadratic residue mod 5 and
+        // doesn't give a genuine degree-2 extension.
+        assert!(validate_field(5, 4).is_err());
+    }
+
+    #[test]
+    fn point_add_matches_known_affine_sum() {
+        let (a, b) = default_curve();
+        // (0, 1) is an affine point on y^2 = x^3 + x + 1 over F_5; the affine
+        // tangent rule gives 2*(0, 1) = (4, 2), and doubling it via point_add
+        // must agree with both that and point_mul(2, ...).
+        let p = Point::from_affine(Fp2::new(P, N, 0, 0), Fp2::new(P, N, 1, 0));
+        let doubled = point_add(p, p, a, b);
+        let via_mul = point_mul(2, p, a, b);
+        assert_eq!(doubled, Point::from_affine(Fp2::new(P, N, 4, 0), Fp2::new(P, N, 2, 0)));
+        assert_eq!(doubled, via_mul);
+    }
+
+    #[test]
+    fn point_add_with_infinity_is_identity() {
+        let (a, b) = default_curve();
+        let p = Point::from_affine(Fp2::new(P, N, 0, 0), Fp2::new(P, N, 1, 0));
+        let o = Point::at_infinity(a.p, a.n);
+        assert_eq!(point_add(p, o, a, b), p);
+        assert_eq!(point_add(o, p, a, b), p);
+    }
+
+    #[test]
+    fn batch_normalize_matches_per_point_to_affine() {
+        let (a, b) = default_curve();
+        let p = Point::from_affine(Fp2::new(P, N, 0, 0), Fp2::new(P, N, 1, 0));
+        let mut points = vec![
+            p,
+            point_mul(2, p, a, b),
+            Point::at_infinity(a.p, a.n),
+            point_mul(3, p, a, b),
+        ];
+        let expected: Vec<_> = points.iter().map(|pt| pt.to_affine()).collect();
+
+        batch_normalize(&mut points);
+
+        for (point, expected) in points.iter().zip(expected) {
+            assert_eq!(point.to_affine(), expected);
+        }
+    }
+
+    #[test]
+    fn batch_normalize_sets_z_to_one_for_finite_points() {
+        let (a, b) = default_curve();
+        let p = Point::from_affine(Fp2::new(P, N, 0, 0), Fp2::new(P, N, 1, 0));
+        let mut points = vec![point_mul(2, p, a, b), point_mul(3, p, a, b)];
+
+        batch_normalize(&mut points);
+
+        for point in &points {
+            assert!(!point.is_at_infinity());
+            assert_eq!(point.z, Fp2::one(a.p, a.n));
+        }
+    }
+
+    #[test]
+    fn tate_pairing_self_pairing_does_not_panic() {
+        let (a, b) = default_curve();
+        let r = 3;
+        let field_elements = {
+            let mut elements = Vec::new();
+            for x in 0..P {
+                for y in 0..P {
+                    elements.push(Fp2::new(P, N, x, y));
+                }
+            }
+            elements
+        };
+        let torsion_points = find_full_r_torsion_points(r, a, b, &field_elements);
+
+        // Every r-torsion point self-paired against itself must either
+        // return a value or a clean degeneracy error, never panic.
+        for p in &torsion_points {
+            let _ = tate_pairing(*p, *p, r, a, b);
+        }
+    }
+
+    #[test]
+    fn tate_pairing_rejects_q_on_degenerate_line_instead_of_panicking() {
+        let (a, b) = default_curve();
+        // (2, 1) is a 3-torsion point on the default demo curve; pairing it
+        // against itself walks Q along the very line the Miller loop
+        // evaluates, which used to divide by zero instead of returning an
+        // error.
+        let p = Point::from_affine(Fp2::new(P, N, 2, 0), Fp2::new(P, N, 1, 0));
+        assert!(tate_pairing(p, p, 3, a, b).is_err());
+    }
+
+    #[test]
+    fn tate_pairing_of_infinity_is_trivial() {
+        let (a, b) = default_curve();
+        let o = Point::at_infinity(a.p, a.n);
+        let p = Point::from_affine(Fp2::new(P, N, 1, 0), Fp2::new(P, N, 1, 0));
+        assert_eq!(tate_pairing(o, p, 3, a, b).unwrap(), Fp2::one(a.p, a.n));
+        assert_eq!(tate_pairing(p, o, 3, a, b).unwrap(), Fp2::one(a.p, a.n));
+    }
+
+    #[test]
+    fn tate_pairing_is_bilinear_in_the_first_argument() {
+        // The defining property of a pairing: e(aP, Q) == e(P, Q)^a. Sweep
+        // every non-degenerate pair of full 3-torsion points and a few
+        // multiples `a` to catch the sign/degenerate-case bugs a single
+        // example can miss.
+        let (a_curve, b_curve) = default_curve();
+        let r = 3;
+        let field_elements = {
+            let mut elements = Vec::new();
+            for x in 0..P {
+                for y in 0..P {
+                    elements.push(Fp2::new(P, N, x, y));
+                }
+            }
+            elements
+        };
+        let torsion_points = find_full_r_torsion_points(r, a_curve, b_curve, &field_elements);
+
+        let mut checked = 0;
+        for p in &torsion_points {
+            if p.is_at_infinity() {
+                continue;
+            }
+            for q in &torsion_points {
+                if q.is_at_infinity() {
+                    continue;
+                }
+                for k in 1..=2u8 {
+                    let kp = point_mul(k, *p, a_curve, b_curve);
+                    let (Ok(e_p_q), Ok(e_kp_q)) = (
+                        tate_pairing(*p, *q, r, a_curve, b_curve),
+                        tate_pairing(kp, *q, r, a_curve, b_curve),
+                    ) else {
+                        continue;
+                    };
+                    assert_eq!(e_kp_q, e_p_q.pow(k as u32), "e({}P, Q) != e(P, Q)^{}", k, k);
+                    checked += 1;
+                }
+            }
+        }
+        // Sanity check the sweep actually exercised non-degenerate pairs
+        // instead of vacuously passing because every pairing errored out.
+        assert!(checked > 0);
+    }
+
+    #[test]
+    fn parse_fp2_reduces_negative_coefficients_mod_p_not_256() {
+        // -1, -2, -3 mod 5 are 4, 3, 2; reducing mod 256 first (as the old
+        // code did) gave 255, 254, 253 mod 5 = 0, 4, 3 instead.
+        assert_eq!(parse_fp2("-1", P, N).unwrap(), Fp2::new(P, N, 4, 0));
+        assert_eq!(parse_fp2("-2", P, N).unwrap(), Fp2::new(P, N, 3, 0));
+        assert_eq!(parse_fp2("-3", P, N).unwrap(), Fp2::new(P, N, 2, 0));
+        assert_eq!(parse_fp2("-1 + -2t", P, N).unwrap(), Fp2::new(P, N, 4, 3));
+    }
+}
@@ -0,0 +1,129 @@
+//! Python bindings for the field, curve, and pairing primitives, built with
+//! PyO3 and enabled via the `pyo3` feature.
+//!
+//! Mirrors the pattern used by crates such as plexcryptool, which expose
+//! their ECC types as Python classes so the heavy arithmetic stays in Rust
+//! while torsion-group and G₂ experiments can be scripted from Python or
+//! Jupyter.
+
+use pyo3::prelude::*;
+
+use crate::{find_full_r_torsion_points, find_g2_points, tate_pairing, validate_field, Fp2, Point};
+
+/// Python-visible wrapper around [`Fp2`], an element of `F_{p^2}`.
+#[pyclass(name = "Fp2")]
+#[derive(Clone, Copy)]
+struct PyFp2(Fp2);
+
+#[pymethods]
+impl PyFp2 {
+    #[new]
+    fn new(p: u8, n: u8, a: u8, b: u8) -> PyResult<Self> {
+        validate_field(p, n).map_err(pyo3::exceptions::PyValueError::new_err)?;
+        Ok(PyFp2(Fp2::new(p, n, a, b)))
+    }
+
+    fn add(&self, other: &PyFp2) -> PyFp2 {
+        PyFp2(self.0.add(other.0))
+    }
+
+    fn sub(&self, other: &PyFp2) -> PyFp2 {
+        PyFp2(self.0.sub(other.0))
+    }
+
+    fn mul(&self, other: &PyFp2) -> PyFp2 {
+        PyFp2(self.0.mul(other.0))
+    }
+
+    fn div(&self, other: &PyFp2) -> PyFp2 {
+        PyFp2(self.0.div(other.0))
+    }
+
+    fn inverse(&self) -> PyFp2 {
+        PyFp2(self.0.inverse())
+    }
+
+    fn frobenius(&self) -> PyFp2 {
+        PyFp2(self.0.frobenius())
+    }
+
+    fn __repr__(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// Python-visible wrapper around [`Point`], a point on `y^2 = x^3 + ax + b`.
+#[pyclass(name = "Point")]
+#[derive(Clone, Copy)]
+struct PyPoint(Point);
+
+#[pymethods]
+impl PyPoint {
+    #[staticmethod]
+    fn from_affine(x: &PyFp2, y: &PyFp2) -> PyPoint {
+        PyPoint(Point::from_affine(x.0, y.0))
+    }
+
+    #[staticmethod]
+    fn at_infinity(p: u8, n: u8) -> PyPoint {
+        PyPoint(Point::at_infinity(p, n))
+    }
+
+    fn is_at_infinity(&self) -> bool {
+        self.0.is_at_infinity()
+    }
+
+    fn __repr__(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+fn field_elements(p: u8, n: u8) -> Vec<Fp2> {
+    let mut elements = Vec::new();
+    for a in 0..p {
+        for b in 0..p {
+            elements.push(Fp2::new(p, n, a, b));
+        }
+    }
+    elements
+}
+
+/// Finds the full `r`-torsion points of `y^2 = x^3 + a*x + b` over `F_{p^2}`.
+#[pyfunction]
+fn find_full_r_torsion_points_py(r: u8, a: &PyFp2, b: &PyFp2) -> Vec<PyPoint> {
+    let field_elements = field_elements(a.0.p, a.0.n);
+    find_full_r_torsion_points(r, a.0, b.0, &field_elements)
+        .into_iter()
+        .map(PyPoint)
+        .collect()
+}
+
+/// Finds the G₂ (Frobenius-eigenspace) points of `y^2 = x^3 + a*x + b` over `F_{p^2}`.
+#[pyfunction]
+fn find_g2_points_py(a: &PyFp2, b: &PyFp2) -> Vec<PyPoint> {
+    let field_elements = field_elements(a.0.p, a.0.n);
+    find_g2_points(a.0, b.0, &field_elements)
+        .into_iter()
+        .map(PyPoint)
+        .collect()
+}
+
+/// Evaluates the Tate pairing `e(P, Q)` via Miller's algorithm. Raises a
+/// `ValueError` if `Q` is degenerate for this `P` (see [`tate_pairing`]).
+#[pyfunction]
+fn tate_pairing_py(p: &PyPoint, q: &PyPoint, r: u8, a: &PyFp2, b: &PyFp2) -> PyResult<PyFp2> {
+    tate_pairing(p.0, q.0, r, a.0, b.0)
+        .map(PyFp2)
+        .map_err(pyo3::exceptions::PyValueError::new_err)
+}
+
+/// Registers the `pairing_groups` Python module.
+#[pymodule]
+fn pairing_groups(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<PyFp2>()?;
+    m.add_class::<PyPoint>()?;
+    m.add_function(wrap_pyfunction!(find_full_r_torsion_points_py, m)?)?;
+    m.add_function(wrap_pyfunction!(find_g2_points_py, m)?)?;
+    m.add_function(wrap_pyfunction!(tate_pairing_py, m)?)?;
+    Ok(())
+}